@@ -0,0 +1,166 @@
+//! Domain-typed signatures, distinguishing the three incompatible RFC 8032
+//! signing modes (`Ed25519`, `Ed25519ph`, `Ed25519ctx`) at the type level.
+//!
+//! All three modes produce byte-identical 64-byte signatures, but a
+//! signature produced under one mode must never be verified as though it
+//! were produced under another. [`DomainSignature<D>`] wraps a plain
+//! [`Signature`] with a zero-cost [`PhantomData<D>`] marker so that mixing
+//! up domains is a compile error rather than a runtime footgun. This
+//! mirrors the `Signature<D: Domain>` pattern used by decaf377-rdsa for
+//! distinguishing `Binding`/`SpendAuth` signatures.
+
+use crate::Signature;
+use core::fmt;
+use core::marker::PhantomData;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A RFC 8032 Ed25519 signing domain.
+///
+/// This trait is sealed: it can only be implemented by the marker types
+/// defined in this crate ([`PureEd25519`], [`Ed25519ph`], [`Ed25519ctx`]).
+pub trait Domain: sealed::Sealed + Copy + Clone + fmt::Debug {
+    /// Human-readable name of this domain, e.g. `"Ed25519"`.
+    const NAME: &'static str;
+
+    /// Does this domain's signing/verification procedure prehash the
+    /// message before processing it (`Ed25519ph`), as opposed to
+    /// operating on the message directly (`Ed25519`, `Ed25519ctx`)?
+    const PREHASHED: bool;
+
+    /// Maximum length in bytes of the optional context string `F`
+    /// associated with this domain. `Ed25519` (pure, contextless) permits
+    /// none; `Ed25519ph` and `Ed25519ctx` permit up to 255 bytes per
+    /// RFC 8032 §5.1.
+    const MAX_CONTEXT_LEN: usize;
+}
+
+/// Marker type for plain, contextless Ed25519 signatures (RFC 8032 §5.1,
+/// `Ed25519`).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PureEd25519;
+
+impl sealed::Sealed for PureEd25519 {}
+
+impl Domain for PureEd25519 {
+    const NAME: &'static str = "Ed25519";
+    const PREHASHED: bool = false;
+    const MAX_CONTEXT_LEN: usize = 0;
+}
+
+/// Marker type for prehashed Ed25519 signatures (RFC 8032 §5.1, `Ed25519ph`).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Ed25519ph;
+
+impl sealed::Sealed for Ed25519ph {}
+
+impl Domain for Ed25519ph {
+    const NAME: &'static str = "Ed25519ph";
+    const PREHASHED: bool = true;
+    const MAX_CONTEXT_LEN: usize = 255;
+}
+
+/// Marker type for contextual, non-prehashed Ed25519 signatures
+/// (RFC 8032 §5.1, `Ed25519ctx`).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Ed25519ctx;
+
+impl sealed::Sealed for Ed25519ctx {}
+
+impl Domain for Ed25519ctx {
+    const NAME: &'static str = "Ed25519ctx";
+    const PREHASHED: bool = false;
+    const MAX_CONTEXT_LEN: usize = 255;
+}
+
+/// An Ed25519 [`Signature`] tagged with the [`Domain`] it was produced
+/// under.
+///
+/// This is a zero-cost wrapper: it has the same layout as `Signature` plus
+/// a `PhantomData<D>` marker, and never changes the raw `R`/`s` bytes.
+/// Conversions to and from the untyped [`Signature`] are explicit via
+/// [`DomainSignature::tag`] and [`DomainSignature::untag`] (or the
+/// corresponding [`From`] impls), so that a prehashed signature can't be
+/// passed where a pure one is expected without an explicit (and
+/// explicitly named) conversion.
+#[derive(Copy, Clone)]
+pub struct DomainSignature<D: Domain> {
+    signature: Signature,
+    domain: PhantomData<D>,
+}
+
+impl<D: Domain> DomainSignature<D> {
+    /// Tag a plain [`Signature`] as having been produced under domain `D`.
+    ///
+    /// This does not validate the signature in any way; it only records
+    /// the caller's claim about which RFC 8032 mode produced it.
+    pub fn tag(signature: Signature) -> Self {
+        Self {
+            signature,
+            domain: PhantomData,
+        }
+    }
+
+    /// Discard the domain tag, recovering the untyped [`Signature`].
+    pub fn untag(self) -> Signature {
+        self.signature
+    }
+
+    /// Borrow the untyped [`Signature`].
+    pub fn as_signature(&self) -> &Signature {
+        &self.signature
+    }
+}
+
+impl<D: Domain> From<Signature> for DomainSignature<D> {
+    fn from(signature: Signature) -> Self {
+        Self::tag(signature)
+    }
+}
+
+impl<D: Domain> From<DomainSignature<D>> for Signature {
+    fn from(tagged: DomainSignature<D>) -> Self {
+        tagged.untag()
+    }
+}
+
+impl<D: Domain> AsRef<Signature> for DomainSignature<D> {
+    fn as_ref(&self) -> &Signature {
+        self.as_signature()
+    }
+}
+
+impl<D: Domain> core::ops::Deref for DomainSignature<D> {
+    type Target = Signature;
+
+    fn deref(&self) -> &Signature {
+        self.as_signature()
+    }
+}
+
+impl<D: Domain> Eq for DomainSignature<D> {}
+
+impl<D: Domain> PartialEq for DomainSignature<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.signature == other.signature
+    }
+}
+
+impl<D: Domain> fmt::Debug for DomainSignature<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(D::NAME).field(&self.signature).finish()
+    }
+}
+
+/// Plain Ed25519 signature, tagged as having been produced in pure
+/// (contextless) mode.
+pub type PureSignature = DomainSignature<PureEd25519>;
+
+/// Ed25519 signature, tagged as having been produced in prehashed mode.
+pub type Ed25519phSignature = DomainSignature<Ed25519ph>;
+
+/// Ed25519 signature, tagged as having been produced in contextual,
+/// non-prehashed mode.
+pub type Ed25519ctxSignature = DomainSignature<Ed25519ctx>;
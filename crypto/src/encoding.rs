@@ -0,0 +1,183 @@
+//! Base58, Base64, and hex string encodings for [`Signature`].
+//!
+//! Gated on the `encoding` feature (which requires `alloc`). This is in
+//! addition to the `hex`-based [`core::fmt::Display`]/[`core::fmt::Debug`]
+//! impls, which are unconditionally available.
+
+use crate::{Error, Signature, SignatureBytes};
+use alloc::string::String;
+use core::str::FromStr;
+
+/// Explicit string encoding to parse a [`Signature`] with, for use with
+/// [`Signature::from_str_with_encoding`] when the encoding of an input
+/// string is already known and auto-detection via [`FromStr`] is
+/// undesirable (e.g. parsing untrusted, ambiguously-encoded input).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum StringEncoding {
+    /// Hexadecimal (upper or lower case).
+    Hex,
+    /// Base58, as used by Solana-style tooling.
+    Base58,
+    /// Base64 (standard alphabet).
+    Base64,
+}
+
+impl Signature {
+    /// Encode this signature as a hexadecimal string.
+    pub fn to_hex(&self) -> String {
+        alloc::format!("{:x}", self)
+    }
+
+    /// Decode an Ed25519 signature from a hexadecimal string.
+    ///
+    /// # Returns
+    /// - `Ok` on success
+    /// - `Err` if the input is not exactly 128 hex digits
+    pub fn from_hex(s: &str) -> signature::Result<Self> {
+        let mut bytes: SignatureBytes = [0u8; Self::BYTE_SIZE];
+        crate::hex::decode_hex(s, &mut bytes).map_err(|_| Error::new())?;
+        Ok(Self::from_bytes(&bytes))
+    }
+
+    /// Encode this signature as a Base58 string.
+    pub fn to_base58(&self) -> String {
+        bs58::encode(self.to_bytes()).into_string()
+    }
+
+    /// Decode an Ed25519 signature from a Base58 string.
+    ///
+    /// # Returns
+    /// - `Ok` on success
+    /// - `Err` if the decoded value is not exactly 64 bytes
+    pub fn from_base58(s: &str) -> signature::Result<Self> {
+        let decoded = bs58::decode(s).into_vec().map_err(|_| Error::new())?;
+        SignatureBytes::try_from(decoded.as_slice())
+            .map(|bytes| Self::from_bytes(&bytes))
+            .map_err(|_| Error::new())
+    }
+
+    /// Encode this signature as a Base64 string (standard alphabet).
+    pub fn to_base64(&self) -> String {
+        use base64ct::Encoding;
+
+        base64ct::Base64::encode_string(&self.to_bytes())
+    }
+
+    /// Decode an Ed25519 signature from a Base64 string (standard
+    /// alphabet).
+    ///
+    /// # Returns
+    /// - `Ok` on success
+    /// - `Err` if the decoded value is not exactly 64 bytes
+    pub fn from_base64(s: &str) -> signature::Result<Self> {
+        use base64ct::Encoding;
+
+        let mut bytes: SignatureBytes = [0u8; Self::BYTE_SIZE];
+        let decoded = base64ct::Base64::decode(s, &mut bytes).map_err(|_| Error::new())?;
+
+        if decoded.len() != Self::BYTE_SIZE {
+            return Err(Error::new());
+        }
+
+        Ok(Self::from_bytes(&bytes))
+    }
+
+    /// Parse a signature from a string using an explicitly specified
+    /// encoding, bypassing the auto-detection performed by
+    /// [`Signature::from_str`][FromStr::from_str].
+    pub fn from_str_with_encoding(s: &str, encoding: StringEncoding) -> signature::Result<Self> {
+        match encoding {
+            StringEncoding::Hex => Self::from_hex(s),
+            StringEncoding::Base58 => Self::from_base58(s),
+            StringEncoding::Base64 => Self::from_base64(s),
+        }
+    }
+}
+
+impl FromStr for Signature {
+    type Err = Error;
+
+    /// Parse a signature from a hex, Base58, or Base64 string, detecting
+    /// which encoding was used based on length and alphabet.
+    ///
+    /// Hex is tried first since it has an unambiguous, fixed length
+    /// (128 digits); Base58 and Base64 overlap in alphabet and length, so
+    /// Base58 is tried before falling back to Base64. Callers that know
+    /// the encoding ahead of time should prefer
+    /// [`Signature::from_str_with_encoding`].
+    fn from_str(s: &str) -> Result<Self, Error> {
+        if s.len() == Self::BYTE_SIZE * 2 && s.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Self::from_hex(s);
+        }
+
+        Self::from_base58(s).or_else(|_| Self::from_base64(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Signature {
+        Signature::from_components([0x11; 32], [0x22; 32])
+    }
+
+    #[test]
+    fn hex_round_trip() {
+        let sig = example();
+        assert_eq!(Signature::from_hex(&sig.to_hex()).unwrap(), sig);
+    }
+
+    #[test]
+    fn hex_rejects_wrong_length() {
+        assert!(Signature::from_hex("1122").is_err());
+    }
+
+    #[test]
+    fn base58_round_trip() {
+        let sig = example();
+        assert_eq!(Signature::from_base58(&sig.to_base58()).unwrap(), sig);
+    }
+
+    #[test]
+    fn base58_rejects_wrong_length() {
+        assert!(Signature::from_base58(&bs58::encode([0u8; 32]).into_string()).is_err());
+    }
+
+    #[test]
+    fn base64_round_trip() {
+        let sig = example();
+        assert_eq!(Signature::from_base64(&sig.to_base64()).unwrap(), sig);
+    }
+
+    #[test]
+    fn base64_rejects_wrong_length() {
+        use base64ct::Encoding;
+        assert!(Signature::from_base64(&base64ct::Base64::encode_string(&[0u8; 32])).is_err());
+    }
+
+    #[test]
+    fn from_str_detects_hex() {
+        let sig = example();
+        assert_eq!(sig.to_hex().parse::<Signature>().unwrap(), sig);
+    }
+
+    #[test]
+    fn from_str_detects_base58() {
+        let sig = example();
+        assert_eq!(sig.to_base58().parse::<Signature>().unwrap(), sig);
+    }
+
+    #[test]
+    fn from_str_detects_base64() {
+        let sig = example();
+        assert_eq!(sig.to_base64().parse::<Signature>().unwrap(), sig);
+    }
+
+    #[test]
+    fn from_str_with_encoding_rejects_mismatched_encoding() {
+        let sig = example();
+        let hex = sig.to_hex();
+        assert!(Signature::from_str_with_encoding(&hex, StringEncoding::Base58).is_err());
+    }
+}
@@ -0,0 +1,84 @@
+//! Hexadecimal encoding support.
+
+use crate::{ComponentBytes, Signature};
+use core::fmt;
+
+impl fmt::LowerHex for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.r_bytes().iter().chain(self.s_bytes().iter()) {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::UpperHex for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.r_bytes().iter().chain(self.s_bytes().iter()) {
+            write!(f, "{:02X}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Helper for formatting an individual `R`/`s` component as uppercase hex
+/// inside a `Debug` impl.
+pub(crate) struct ComponentFormatter<'a>(pub(crate) &'a ComponentBytes);
+
+impl fmt::Debug for ComponentFormatter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"")?;
+        for byte in self.0 {
+            write!(f, "{:02X}", byte)?;
+        }
+        write!(f, "\"")
+    }
+}
+
+/// Formats an arbitrary byte slice as lowercase hex, for use with
+/// `Serializer::collect_str` where an allocation-free `Display` is needed
+/// (e.g. `serde` human-readable serialization).
+#[cfg(feature = "serde")]
+pub(crate) struct HexBytes<'a>(pub(crate) &'a [u8]);
+
+#[cfg(feature = "serde")]
+impl fmt::Display for HexBytes<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse a single ASCII hex digit into its 4-bit value.
+#[cfg(any(feature = "serde", feature = "encoding"))]
+pub(crate) fn hex_nibble(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Parse exactly `out.len() * 2` hex digits from `s` into `out`.
+///
+/// Returns `Err(())` if `s` is the wrong length or contains a non-hex-digit
+/// byte; callers are expected to map this to their own error type.
+#[cfg(any(feature = "serde", feature = "encoding"))]
+pub(crate) fn decode_hex(s: &str, out: &mut [u8]) -> Result<(), ()> {
+    let s = s.as_bytes();
+
+    if s.len() != out.len() * 2 {
+        return Err(());
+    }
+
+    for (byte, chunk) in out.iter_mut().zip(s.chunks(2)) {
+        let hi = hex_nibble(chunk[0]).ok_or(())?;
+        let lo = hex_nibble(chunk[1]).ok_or(())?;
+        *byte = (hi << 4) | lo;
+    }
+
+    Ok(())
+}
@@ -3,6 +3,12 @@ extern crate alloc;
 
 mod hex;
 
+#[cfg(feature = "encoding")]
+mod encoding;
+
+#[cfg(feature = "domain")]
+pub mod domain;
+
 #[cfg(feature = "pkcs8")]
 pub mod pkcs8;
 
@@ -11,6 +17,9 @@ mod serde;
 
 pub use signature::{self, Error, SignatureEncoding};
 
+#[cfg(feature = "encoding")]
+pub use crate::encoding::StringEncoding;
+
 #[cfg(feature = "pkcs8")]
 pub use crate::pkcs8::{KeypairBytes, PublicKeyBytes};
 
@@ -37,13 +46,24 @@ pub type SignatureBytes = [u8; Signature::BYTE_SIZE];
 ///
 /// Signature verification libraries are expected to reject invalid field
 /// elements at the time a signature is verified.
-#[derive(Copy, Clone, Eq, PartialEq)]
+///
+/// `PartialEq`/`Eq` compare the `R` and `s` components directly and may
+/// short-circuit on the first differing byte, which is fine for
+/// deduplication/indexing (`Hash`, `PartialOrd`, and `Ord` are also
+/// provided for exactly this, ordered lexicographically over the 64
+/// concatenated `R || s` bytes) but is not appropriate for comparing a
+/// locally-held signature against one supplied by a possibly-adversarial
+/// peer. For that, enable the `subtle` feature and use
+/// [`Signature::ct_eq`] instead.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
 #[repr(C)]
+#[allow(non_snake_case)]
 pub struct Signature {
     R: ComponentBytes,
     s: ComponentBytes,
 }
 
+#[allow(non_snake_case)]
 impl Signature {
     /// Size of an encoded Ed25519 signature in bytes.
     pub const BYTE_SIZE: usize = COMPONENT_SIZE * 2;
@@ -100,6 +120,89 @@ impl Signature {
     pub fn to_vec(&self) -> Vec<u8> {
         self.to_bytes().to_vec()
     }
+
+    /// Compare this signature with `other` in constant time.
+    ///
+    /// Unlike the derived [`PartialEq`], this does not early-exit on the
+    /// first differing byte, so the time it takes does not leak how many
+    /// leading bytes of `other` matched. Prefer this over `==` when
+    /// comparing a stored signature against one supplied by a
+    /// possibly-adversarial peer (e.g. replay/dedup checks).
+    #[cfg(feature = "subtle")]
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq_choice(other))
+    }
+
+    #[cfg(feature = "subtle")]
+    fn ct_eq_choice(&self, other: &Self) -> subtle::Choice {
+        use subtle::ConstantTimeEq as _;
+        self.R.ct_eq(&other.R) & self.s.ct_eq(&other.s)
+    }
+
+    /// Parse an Ed25519 signature from its byte serialization, rejecting
+    /// it if the `s` component is not in canonical (fully reduced) form.
+    ///
+    /// This enforces the "strict"/ZIP-215-adjacent validity rules followed
+    /// by RFC 8032 §5.1.7: `s` must satisfy `0 <= s < L` where `L` is the
+    /// order of the Ed25519 basepoint. Rejecting non-canonical `s` values
+    /// closes off a source of signature malleability, since a non-reduced
+    /// `s` and its reduction `s mod L` both verify for the same message but
+    /// serialize to different bytes.
+    ///
+    /// # Returns
+    /// - `Ok` if the signature's `s` component is canonical
+    /// - `Err` if `s >= L`
+    pub fn from_bytes_canonical(bytes: &SignatureBytes) -> signature::Result<Self> {
+        let signature = Self::from_bytes(bytes);
+
+        if signature.is_canonical() {
+            Ok(signature)
+        } else {
+            Err(Error::new())
+        }
+    }
+
+    /// Is the `s` component of this signature canonical, i.e. fully reduced
+    /// modulo the order `L` of the Ed25519 basepoint?
+    ///
+    /// `L = 2^252 + 27742317777372353535851937790883648493`.
+    ///
+    /// Two distinct 64-byte encodings can otherwise verify for the same
+    /// message (signature malleability): `s` and `s + k*L` for any `k`
+    /// produce the same scalar mod `L`. Consensus systems that hash
+    /// signatures or use them as unique identifiers should call this (or
+    /// [`Signature::from_bytes_canonical`]) before accepting a signature.
+    pub fn is_canonical(&self) -> bool {
+        is_canonical_scalar(&self.s)
+    }
+}
+
+/// Order `L` of the Ed25519 basepoint, little-endian.
+///
+/// `L = 2^252 + 27742317777372353535851937790883648493`.
+const ORDER_L: ComponentBytes = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
+/// Returns `true` if `scalar`, interpreted as a little-endian 256-bit
+/// integer, is strictly less than the Ed25519 group order `L`.
+///
+/// Compares from the most-significant byte down, which is sufficient for
+/// this pre-check (it need not run in constant time: non-canonical
+/// signatures are rejected outright, so there is nothing secret left to
+/// leak by the time this runs).
+fn is_canonical_scalar(scalar: &ComponentBytes) -> bool {
+    for i in (0..COMPONENT_SIZE).rev() {
+        match scalar[i].cmp(&ORDER_L[i]) {
+            core::cmp::Ordering::Less => return true,
+            core::cmp::Ordering::Greater => return false,
+            core::cmp::Ordering::Equal => continue,
+        }
+    }
+
+    // All bytes equal: scalar == L, which is not < L.
+    false
 }
 
 impl SignatureEncoding for Signature {
@@ -155,4 +258,103 @@ impl fmt::Display for Signature {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:X}", self)
     }
+}
+
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeEq for Signature {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.ct_eq_choice(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `s` as a little-endian 32-byte scalar, with the `R` component fixed
+    /// to all-zero (its value is irrelevant to canonicality).
+    fn signature_with_s(s: ComponentBytes) -> Signature {
+        Signature::from_components([0u8; COMPONENT_SIZE], s)
+    }
+
+    #[test]
+    fn is_canonical_accepts_zero() {
+        assert!(signature_with_s([0u8; COMPONENT_SIZE]).is_canonical());
+    }
+
+    #[test]
+    fn is_canonical_accepts_l_minus_one() {
+        // L - 1, little-endian.
+        let mut s = ORDER_L;
+        s[0] -= 1;
+        assert!(signature_with_s(s).is_canonical());
+    }
+
+    #[test]
+    fn is_canonical_rejects_l() {
+        assert!(!signature_with_s(ORDER_L).is_canonical());
+    }
+
+    #[test]
+    fn is_canonical_rejects_l_plus_one() {
+        let mut s = ORDER_L;
+        s[0] += 1;
+        assert!(!signature_with_s(s).is_canonical());
+    }
+
+    #[test]
+    fn is_canonical_rejects_all_ff() {
+        assert!(!signature_with_s([0xffu8; COMPONENT_SIZE]).is_canonical());
+    }
+
+    #[test]
+    fn from_bytes_canonical_matches_is_canonical() {
+        let mut bytes = [0u8; Signature::BYTE_SIZE];
+        bytes[COMPONENT_SIZE..].copy_from_slice(&ORDER_L);
+        assert!(Signature::from_bytes_canonical(&bytes).is_err());
+
+        bytes[COMPONENT_SIZE] = 0;
+        assert!(Signature::from_bytes_canonical(&bytes).is_ok());
+    }
+
+    #[test]
+    fn ord_is_lexicographic_over_r_then_s() {
+        let lo = Signature::from_components([0u8; COMPONENT_SIZE], [0xffu8; COMPONENT_SIZE]);
+        let hi = Signature::from_components([1u8; COMPONENT_SIZE], [0u8; COMPONENT_SIZE]);
+        assert!(lo < hi);
+
+        let same_r_lo = Signature::from_components([0u8; COMPONENT_SIZE], [0u8; COMPONENT_SIZE]);
+        let same_r_hi = Signature::from_components([0u8; COMPONENT_SIZE], [1u8; COMPONENT_SIZE]);
+        assert!(same_r_lo < same_r_hi);
+    }
+
+    #[test]
+    fn hash_agrees_with_eq() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of(sig: &Signature) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            sig.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a = Signature::from_components([7u8; COMPONENT_SIZE], [8u8; COMPONENT_SIZE]);
+        let b = Signature::from_components([7u8; COMPONENT_SIZE], [8u8; COMPONENT_SIZE]);
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[cfg(feature = "subtle")]
+    #[test]
+    fn ct_eq_agrees_with_eq() {
+        let a = Signature::from_components([9u8; COMPONENT_SIZE], [10u8; COMPONENT_SIZE]);
+        let b = Signature::from_components([9u8; COMPONENT_SIZE], [10u8; COMPONENT_SIZE]);
+        let c = Signature::from_components([9u8; COMPONENT_SIZE], [11u8; COMPONENT_SIZE]);
+
+        assert!(a.ct_eq(&b));
+        assert!(!a.ct_eq(&c));
+        assert_eq!(a == b, a.ct_eq(&b));
+        assert_eq!(a == c, a.ct_eq(&c));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,82 @@
+//! PKCS#8 private/public key material for Ed25519.
+//!
+//! These types store raw Ed25519 key bytes as extracted from (or destined
+//! for) a PKCS#8 `PrivateKeyInfo`/`SubjectPublicKeyInfo` document. They are
+//! intentionally minimal containers, mirroring [`Signature`](crate::Signature)'s
+//! role as a dumb byte container for signing/verification libraries to
+//! build on top of.
+
+/// Size of a raw Ed25519 public or secret key in bytes.
+const KEY_SIZE: usize = 32;
+
+/// Raw bytes of an Ed25519 public key, as stored in a PKCS#8
+/// `SubjectPublicKeyInfo` document.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct PublicKeyBytes(pub [u8; KEY_SIZE]);
+
+impl PublicKeyBytes {
+    /// Borrow the raw public key bytes.
+    pub fn as_bytes(&self) -> &[u8; KEY_SIZE] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for PublicKeyBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; KEY_SIZE]> for PublicKeyBytes {
+    fn from(bytes: [u8; KEY_SIZE]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl core::fmt::Debug for PublicKeyBytes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("PublicKeyBytes")
+            .field(&crate::hex::ComponentFormatter(&self.0))
+            .finish()
+    }
+}
+
+/// Raw bytes of an Ed25519 keypair, as stored in a PKCS#8 `PrivateKeyInfo`
+/// document.
+///
+/// The public key is optional because PKCS#8 `PrivateKeyInfo` documents may
+/// omit it (it is always recoverable from the secret key, but some
+/// encoders include it anyway as an optional attribute).
+#[derive(Clone)]
+pub struct KeypairBytes {
+    /// Raw bytes of the secret (seed) key.
+    pub secret_key: [u8; KEY_SIZE],
+
+    /// Raw bytes of the public key, if present in the source document.
+    pub public_key: Option<PublicKeyBytes>,
+}
+
+impl core::fmt::Debug for KeypairBytes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Never format `secret_key`: `Debug` output routinely ends up in
+        // logs, panic messages, and error reports, and this is private key
+        // material.
+        f.debug_struct("KeypairBytes")
+            .field("secret_key", &"[REDACTED]")
+            .field("public_key", &self.public_key)
+            .finish()
+    }
+}
+
+/// Zeroizes [`KeypairBytes::secret_key`] on drop.
+///
+/// Gated behind the `zeroize` feature: pulling in the `zeroize` crate is an
+/// opt-in hardening measure, not a default cost imposed on every consumer
+/// of this otherwise-minimal container.
+#[cfg(feature = "zeroize")]
+impl Drop for KeypairBytes {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+        self.secret_key.zeroize();
+    }
+}
@@ -0,0 +1,322 @@
+//! `serde` support for [`Signature`], gated on the `serde` feature.
+//!
+//! Human-readable formats (JSON, YAML, TOML, ...) serialize a `Signature`
+//! as a hex string rather than a 64-element byte sequence, for readability
+//! and compactness. Binary formats (bincode, CBOR, ...) keep the existing
+//! fixed-size byte array encoding. Deserialization accepts both a string
+//! and a byte sequence regardless of format, so a human-readable value can
+//! still be read back by a deserializer that reports
+//! `is_human_readable() == false` (and vice versa).
+
+use crate::hex::HexBytes;
+use crate::{Error, Signature, SignatureBytes};
+use core::fmt;
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::ser::{SerializeTuple, Serializer};
+use serde::{Deserialize, Serialize};
+
+impl Serialize for Signature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&HexBytes(&self.to_bytes()))
+        } else {
+            // `[u8; 64]` has no direct `Serialize` impl (serde only special-cases
+            // arrays up to 32 elements), and letting it unsize-coerce to `&[u8]`
+            // would serialize it as a length-prefixed sequence instead of the
+            // fixed-size tuple `Signature::deserialize` expects. Serialize each
+            // byte as an explicit fixed-size tuple to match.
+            let bytes = self.to_bytes();
+            let mut tup = serializer.serialize_tuple(Self::BYTE_SIZE)?;
+            for byte in &bytes {
+                tup.serialize_element(byte)?;
+            }
+            tup.end()
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Signature {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(SignatureBytesVisitor)
+        } else {
+            deserializer.deserialize_tuple(Signature::BYTE_SIZE, SignatureBytesVisitor)
+        }
+    }
+}
+
+struct SignatureStrVisitor;
+
+impl de::Visitor<'_> for SignatureStrVisitor {
+    type Value = Signature;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a hex-encoded Ed25519 signature")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Signature, E> {
+        let mut bytes: SignatureBytes = [0u8; Signature::BYTE_SIZE];
+        crate::hex::decode_hex(s, &mut bytes)
+            .map_err(|_| de::Error::custom(Error::new()))?;
+        Ok(Signature::from_bytes(&bytes))
+    }
+}
+
+struct SignatureBytesVisitor;
+
+impl<'de> Visitor<'de> for SignatureBytesVisitor {
+    type Value = Signature;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a hex string or 64 bytes of an Ed25519 signature")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Signature, E> {
+        SignatureStrVisitor.visit_str(s)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Signature, A::Error> {
+        let mut bytes: SignatureBytes = [0u8; Signature::BYTE_SIZE];
+
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+
+        Ok(Signature::from_bytes(&bytes))
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Signature, E> {
+        Signature::from_slice(bytes).map_err(|_| de::Error::invalid_length(bytes.len(), &self))
+    }
+}
+
+impl Serialize for HexBytes<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Signature {
+        Signature::from_components([0x11; 32], [0x22; 32])
+    }
+
+    #[test]
+    fn json_round_trip_is_a_hex_string() {
+        let sig = example();
+        let json = serde_json::to_string(&sig).unwrap();
+        assert_eq!(json, alloc::format!("\"{:x}\"", sig));
+        assert_eq!(serde_json::from_str::<Signature>(&json).unwrap(), sig);
+    }
+
+    #[test]
+    fn bincode_round_trip_is_fixed_size_bytes() {
+        let sig = example();
+        let encoded = bincode::serialize(&sig).unwrap();
+        assert_eq!(encoded.len(), Signature::BYTE_SIZE);
+        assert_eq!(bincode::deserialize::<Signature>(&encoded).unwrap(), sig);
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+mod pkcs8_serde {
+    use super::*;
+    use crate::pkcs8::{KeypairBytes, PublicKeyBytes};
+
+    impl Serialize for PublicKeyBytes {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                serializer.collect_str(&HexBytes(&self.0))
+            } else {
+                self.0.serialize(serializer)
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for PublicKeyBytes {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_str(KeyBytesVisitor)
+            } else {
+                <[u8; 32]>::deserialize(deserializer).map(PublicKeyBytes)
+            }
+        }
+    }
+
+    impl Serialize for KeypairBytes {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            use serde::ser::SerializeStruct;
+
+            let human_readable = serializer.is_human_readable();
+            let mut state = serializer.serialize_struct("KeypairBytes", 2)?;
+
+            if human_readable {
+                state.serialize_field("secret_key", &HexBytes(&self.secret_key))?;
+            } else {
+                state.serialize_field("secret_key", &self.secret_key)?;
+            }
+
+            state.serialize_field("public_key", &self.public_key)?;
+            state.end()
+        }
+    }
+
+    impl<'de> Deserialize<'de> for KeypairBytes {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            #[derive(Deserialize)]
+            #[serde(field_identifier, rename_all = "snake_case")]
+            enum Field {
+                SecretKey,
+                PublicKey,
+            }
+
+            struct KeypairVisitor;
+
+            impl<'de> Visitor<'de> for KeypairVisitor {
+                type Value = KeypairBytes;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "an Ed25519 keypair")
+                }
+
+                fn visit_seq<A: SeqAccess<'de>>(
+                    self,
+                    mut seq: A,
+                ) -> Result<KeypairBytes, A::Error> {
+                    let secret_key = seq
+                        .next_element::<RawKey32>()?
+                        .ok_or_else(|| de::Error::invalid_length(0, &self))?
+                        .0;
+                    let public_key = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+                    Ok(KeypairBytes {
+                        secret_key,
+                        public_key,
+                    })
+                }
+
+                fn visit_map<A: de::MapAccess<'de>>(
+                    self,
+                    mut map: A,
+                ) -> Result<KeypairBytes, A::Error> {
+                    let mut secret_key = None;
+                    let mut public_key = None;
+
+                    while let Some(key) = map.next_key()? {
+                        match key {
+                            Field::SecretKey => {
+                                secret_key = Some(map.next_value::<RawKey32>()?.0)
+                            }
+                            Field::PublicKey => public_key = map.next_value()?,
+                        }
+                    }
+
+                    Ok(KeypairBytes {
+                        secret_key: secret_key
+                            .ok_or_else(|| de::Error::missing_field("secret_key"))?,
+                        public_key,
+                    })
+                }
+            }
+
+            deserializer.deserialize_struct(
+                "KeypairBytes",
+                &["secret_key", "public_key"],
+                KeypairVisitor,
+            )
+        }
+    }
+
+    struct KeyBytesVisitor;
+
+    impl de::Visitor<'_> for KeyBytesVisitor {
+        type Value = PublicKeyBytes;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a hex-encoded 32-byte Ed25519 public key")
+        }
+
+        fn visit_str<E: de::Error>(self, s: &str) -> Result<PublicKeyBytes, E> {
+            let mut bytes = [0u8; 32];
+            crate::hex::decode_hex(s, &mut bytes).map_err(|_| de::Error::custom(Error::new()))?;
+            Ok(PublicKeyBytes(bytes))
+        }
+    }
+
+    /// A raw 32-byte key that deserializes from either a hex string
+    /// (human-readable formats) or a byte array (binary formats).
+    struct RawKey32([u8; 32]);
+
+    impl<'de> Deserialize<'de> for RawKey32 {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            if deserializer.is_human_readable() {
+                struct V;
+
+                impl de::Visitor<'_> for V {
+                    type Value = RawKey32;
+
+                    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        write!(f, "a hex-encoded 32-byte key")
+                    }
+
+                    fn visit_str<E: de::Error>(self, s: &str) -> Result<RawKey32, E> {
+                        let mut bytes = [0u8; 32];
+                        crate::hex::decode_hex(s, &mut bytes)
+                            .map_err(|_| de::Error::custom(Error::new()))?;
+                        Ok(RawKey32(bytes))
+                    }
+                }
+
+                deserializer.deserialize_str(V)
+            } else {
+                <[u8; 32]>::deserialize(deserializer).map(RawKey32)
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn example_keypair() -> KeypairBytes {
+            KeypairBytes {
+                secret_key: [0x33; 32],
+                public_key: Some(PublicKeyBytes([0x44; 32])),
+            }
+        }
+
+        #[test]
+        fn public_key_json_round_trip_is_a_hex_string() {
+            let key = PublicKeyBytes([0x44; 32]);
+            let json = serde_json::to_string(&key).unwrap();
+            assert_eq!(json, alloc::format!("\"{}\"", crate::hex::HexBytes(&key.0)));
+            assert_eq!(serde_json::from_str::<PublicKeyBytes>(&json).unwrap(), key);
+        }
+
+        #[test]
+        fn keypair_json_round_trip_hides_nothing_from_equality() {
+            let keypair = example_keypair();
+            let json = serde_json::to_string(&keypair).unwrap();
+            let decoded: KeypairBytes = serde_json::from_str(&json).unwrap();
+            assert_eq!(decoded.secret_key, keypair.secret_key);
+            assert_eq!(decoded.public_key, keypair.public_key);
+        }
+
+        #[test]
+        fn keypair_bincode_round_trip() {
+            let keypair = example_keypair();
+            let encoded = bincode::serialize(&keypair).unwrap();
+            let decoded: KeypairBytes = bincode::deserialize(&encoded).unwrap();
+            assert_eq!(decoded.secret_key, keypair.secret_key);
+            assert_eq!(decoded.public_key, keypair.public_key);
+        }
+    }
+}
+